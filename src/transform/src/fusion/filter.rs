@@ -42,6 +42,9 @@
 //! assert_eq!(expr, correct);
 //! ```
 
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
 use crate::TransformArgs;
 use expr::{MirRelationExpr, MirScalarExpr};
 
@@ -76,12 +79,59 @@ impl Filter {
                 *input = Box::new(inner.take_dangerous());
             }
 
-            for predicate in predicates.iter_mut() {
-                canonicalize_predicate(predicate);
+            // Normalize each predicate and repeatedly split top-level
+            // conjunctions into their conjuncts, so that e.g. `filter(a AND
+            // b)` followed by `filter(a)` dedups against the first conjunct
+            // rather than being kept as two independent predicates. These run
+            // together to a fixpoint because normalization can itself expose
+            // a top-level `And` that wasn't there to begin with, e.g.
+            // `NOT (NOT (a AND b))` normalizes to `a AND b`.
+            let mut i = 0;
+            while i < predicates.len() {
+                normalize_predicate(&mut predicates[i]);
+                if matches!(
+                    &predicates[i],
+                    MirScalarExpr::CallBinary {
+                        func: expr::BinaryFunc::And,
+                        ..
+                    }
+                ) {
+                    if let MirScalarExpr::CallBinary { expr1, expr2, .. } = predicates.swap_remove(i)
+                    {
+                        predicates.push(*expr1);
+                        predicates.push(*expr2);
+                    }
+                } else {
+                    i += 1;
+                }
             }
+
+            // Fold repeated comparisons against the same expression into
+            // their tightest bound (e.g. `x > 3` and `x > 5` become just
+            // `x > 5`), and detect when the resulting bounds can never be
+            // satisfied (e.g. `x >= 5` and `x < 5`).
+            let contradiction = tighten_comparisons(predicates);
+
             predicates.sort();
             predicates.dedup();
 
+            // Constant folding can turn an always-true predicate into a
+            // literal; it contributes nothing to the filter, so drop it
+            // rather than leaving a no-op `filter(true)` behind.
+            predicates.retain(|p| !p.is_literal_true());
+
+            // A predicate that is trivially false or null can never let a row
+            // through, so the whole filter can be replaced by an empty
+            // collection of the same type as its input.
+            if contradiction
+                || predicates
+                    .iter()
+                    .any(|p| p.is_literal_false() || p.is_literal_null())
+            {
+                *relation = MirRelationExpr::constant(vec![], input.typ());
+                return;
+            }
+
             // remove the Filter stage if empty.
             if predicates.is_empty() {
                 *relation = input.take_dangerous();
@@ -90,20 +140,141 @@ impl Filter {
     }
 }
 
-/// Ensures that two equalities are made in a consistent order.
-fn canonicalize_predicate(predicate: &mut MirScalarExpr) {
+/// The rewrite rules applied by [`normalize_predicate`], in order, run to a
+/// fixpoint. Adding an algebraic simplification to this module should mean
+/// adding an entry here rather than growing `Filter::action` itself.
+const NORMALIZATIONS: &[fn(&mut MirScalarExpr)] = &[
+    canonicalize_operand_order,
+    eliminate_double_negation,
+    canonicalize_self_comparison,
+    fold_constants,
+];
+
+/// Rewrites `predicate` by the rules in [`NORMALIZATIONS`] until none of
+/// them change it any further.
+fn normalize_predicate(predicate: &mut MirScalarExpr) {
+    loop {
+        let before = predicate.clone();
+        for rule in NORMALIZATIONS {
+            rule(predicate);
+        }
+        if *predicate == before {
+            break;
+        }
+    }
+}
+
+/// Orders the operands of commutative binary operators consistently, so that
+/// e.g. `a = b` and `b = a` dedup against each other. Recurses into operands
+/// first, so that nested commutative operators (e.g. `(b AND a) = c`) are
+/// themselves canonicalized before the enclosing operator's operands are
+/// compared.
+fn canonicalize_operand_order(predicate: &mut MirScalarExpr) {
+    match predicate {
+        MirScalarExpr::Column(_) | MirScalarExpr::Literal(_, _) | MirScalarExpr::CallNullary(_) => {}
+        MirScalarExpr::CallUnary { expr, .. } => canonicalize_operand_order(expr),
+        MirScalarExpr::CallBinary { func, expr1, expr2 } => {
+            canonicalize_operand_order(expr1);
+            canonicalize_operand_order(expr2);
+            if is_commutative(*func) && expr2 < expr1 {
+                ::std::mem::swap(expr1, expr2);
+            }
+        }
+        MirScalarExpr::CallVariadic { exprs, .. } => {
+            for expr in exprs {
+                canonicalize_operand_order(expr);
+            }
+        }
+        MirScalarExpr::If { cond, then, els } => {
+            canonicalize_operand_order(cond);
+            canonicalize_operand_order(then);
+            canonicalize_operand_order(els);
+        }
+    }
+}
+
+/// The binary functions whose operands can be freely reordered: `Eq`, `And`,
+/// `Or`, and integer and float addition and multiplication, so that e.g.
+/// `a + b > 0` and `b + a > 0` dedup too. Decimal arithmetic is not reordered.
+fn is_commutative(func: expr::BinaryFunc) -> bool {
+    matches!(
+        func,
+        expr::BinaryFunc::Eq
+            | expr::BinaryFunc::And
+            | expr::BinaryFunc::Or
+            | expr::BinaryFunc::AddInt32
+            | expr::BinaryFunc::AddInt64
+            | expr::BinaryFunc::AddFloat32
+            | expr::BinaryFunc::AddFloat64
+            | expr::BinaryFunc::MulInt32
+            | expr::BinaryFunc::MulInt64
+            | expr::BinaryFunc::MulFloat32
+            | expr::BinaryFunc::MulFloat64
+    )
+}
+
+/// Rewrites `NOT (NOT e)` to `e`. Recurses into operands first, so that a
+/// double negation nested under e.g. an `Or` is also normalized, not just one
+/// sitting at the predicate's root.
+fn eliminate_double_negation(predicate: &mut MirScalarExpr) {
+    match predicate {
+        MirScalarExpr::Column(_) | MirScalarExpr::Literal(_, _) | MirScalarExpr::CallNullary(_) => {}
+        MirScalarExpr::CallUnary { expr, .. } => eliminate_double_negation(expr),
+        MirScalarExpr::CallBinary { expr1, expr2, .. } => {
+            eliminate_double_negation(expr1);
+            eliminate_double_negation(expr2);
+        }
+        MirScalarExpr::CallVariadic { exprs, .. } => {
+            for expr in exprs {
+                eliminate_double_negation(expr);
+            }
+        }
+        MirScalarExpr::If { cond, then, els } => {
+            eliminate_double_negation(cond);
+            eliminate_double_negation(then);
+            eliminate_double_negation(els);
+        }
+    }
+
+    let inner = match predicate {
+        MirScalarExpr::CallUnary {
+            func: expr::UnaryFunc::Not,
+            expr: outer,
+        } => match outer.as_ref() {
+            MirScalarExpr::CallUnary {
+                func: expr::UnaryFunc::Not,
+                expr: inner,
+            } => Some((**inner).clone()),
+            _ => None,
+        },
+        _ => None,
+    };
+    if let Some(inner) = inner {
+        *predicate = inner;
+    }
+}
+
+/// Comparison to self is always true unless the element is `Datum::Null`, so
+/// `e = e` rewrites to `NOT (e IS NULL)` -- the same canonical shape already
+/// used for a user-written `e IS NOT NULL` -- so that the two forms dedup
+/// against each other. The rewrite turns a null result into `false`, which a
+/// filter treats alike, so it recurses only into the operands of `And` and
+/// `Or` (e.g. a self-comparison nested under an `Or` is normalized), and not
+/// under e.g. a `NOT`, where `NOT (e = e)` is null but `e IS NULL` is true.
+fn canonicalize_self_comparison(predicate: &mut MirScalarExpr) {
+    if let MirScalarExpr::CallBinary { func, expr1, expr2 } = predicate {
+        if matches!(func, expr::BinaryFunc::And | expr::BinaryFunc::Or) {
+            canonicalize_self_comparison(expr1);
+            canonicalize_self_comparison(expr2);
+        }
+    }
+
     if let MirScalarExpr::CallBinary {
         func: expr::BinaryFunc::Eq,
         expr1,
         expr2,
     } = predicate
     {
-        // Canonically order elements so that deduplication works better.
-        if expr2 < expr1 {
-            ::std::mem::swap(expr1, expr2);
-        }
-
-        // Comparison to self is always true unless the element is `Datum::Null`.
         if expr1 == expr2 {
             *predicate = expr1
                 .clone()
@@ -112,3 +283,411 @@ fn canonicalize_predicate(predicate: &mut MirScalarExpr) {
         }
     }
 }
+
+/// Evaluates any sub-expression of `predicate` down to a literal if every
+/// leaf it references is itself a literal (i.e. it contains no `Column`
+/// references), so that e.g. the `1 < 2` in `x AND (1 < 2)` folds to `true`
+/// even though the enclosing `And` also references a column.
+fn fold_constants(predicate: &mut MirScalarExpr) {
+    match predicate {
+        MirScalarExpr::Column(_) | MirScalarExpr::Literal(_, _) | MirScalarExpr::CallNullary(_) => {}
+        MirScalarExpr::CallUnary { expr, .. } => fold_constants(expr),
+        MirScalarExpr::CallBinary { expr1, expr2, .. } => {
+            fold_constants(expr1);
+            fold_constants(expr2);
+        }
+        MirScalarExpr::CallVariadic { exprs, .. } => {
+            for expr in exprs {
+                fold_constants(expr);
+            }
+        }
+        MirScalarExpr::If { cond, then, els } => {
+            fold_constants(cond);
+            fold_constants(then);
+            fold_constants(els);
+        }
+    }
+
+    if !predicate.is_literal() && is_all_literal(predicate) {
+        let typ = predicate.typ(&[]);
+        let temp_storage = repr::RowArena::new();
+        let evaluated = predicate.eval(&[], &temp_storage);
+        *predicate = MirScalarExpr::literal(evaluated, typ.scalar_type);
+    }
+}
+
+/// Whether every leaf reachable from `expr` is a literal, i.e. `expr`
+/// references no columns and can be evaluated without a row. A `CallNullary`
+/// (e.g. `mz_logical_timestamp`) is not a literal -- it's context-dependent,
+/// not a constant -- so it is not all-literal either.
+fn is_all_literal(expr: &MirScalarExpr) -> bool {
+    match expr {
+        MirScalarExpr::Literal(_, _) => true,
+        MirScalarExpr::Column(_) | MirScalarExpr::CallNullary(_) => false,
+        MirScalarExpr::CallUnary { expr, .. } => is_all_literal(expr),
+        MirScalarExpr::CallBinary { expr1, expr2, .. } => {
+            is_all_literal(expr1) && is_all_literal(expr2)
+        }
+        MirScalarExpr::CallVariadic { exprs, .. } => exprs.iter().all(is_all_literal),
+        MirScalarExpr::If { cond, then, els } => {
+            is_all_literal(cond) && is_all_literal(then) && is_all_literal(els)
+        }
+    }
+}
+
+/// The strongest lower and upper bound known for some expression, each
+/// paired with whether the bound is inclusive.
+#[derive(Default)]
+struct Bounds {
+    lower: Option<(MirScalarExpr, bool)>,
+    upper: Option<(MirScalarExpr, bool)>,
+}
+
+impl Bounds {
+    /// Tightens the lower bound to `literal`, keeping whichever of the old
+    /// and new bound is stronger.
+    fn tighten_lower(&mut self, literal: MirScalarExpr, inclusive: bool) {
+        let replace = match &self.lower {
+            None => true,
+            Some((current, current_inclusive)) => match literal_order(&literal, current) {
+                Some(Ordering::Greater) => true,
+                Some(Ordering::Equal) => *current_inclusive && !inclusive,
+                _ => false,
+            },
+        };
+        if replace {
+            self.lower = Some((literal, inclusive));
+        }
+    }
+
+    /// Tightens the upper bound to `literal`, keeping whichever of the old
+    /// and new bound is stronger.
+    fn tighten_upper(&mut self, literal: MirScalarExpr, inclusive: bool) {
+        let replace = match &self.upper {
+            None => true,
+            Some((current, current_inclusive)) => match literal_order(&literal, current) {
+                Some(Ordering::Less) => true,
+                Some(Ordering::Equal) => *current_inclusive && !inclusive,
+                _ => false,
+            },
+        };
+        if replace {
+            self.upper = Some((literal, inclusive));
+        }
+    }
+}
+
+/// Compares the literal values of two scalar expressions, if both are in
+/// fact literals that evaluated successfully (an erroring literal, e.g. a
+/// divide-by-zero caught at plan time, has no value to compare).
+fn literal_order(a: &MirScalarExpr, b: &MirScalarExpr) -> Option<Ordering> {
+    match (a.as_literal(), b.as_literal()) {
+        (Some(Ok(a)), Some(Ok(b))) => a.partial_cmp(&b),
+        _ => None,
+    }
+}
+
+/// Flips a comparison operator to the equivalent one with its operands
+/// swapped, e.g. `3 < x` becomes `x > 3`.
+fn flip_comparison(func: expr::BinaryFunc) -> expr::BinaryFunc {
+    match func {
+        expr::BinaryFunc::Lt => expr::BinaryFunc::Gt,
+        expr::BinaryFunc::Lte => expr::BinaryFunc::Gte,
+        expr::BinaryFunc::Gt => expr::BinaryFunc::Lt,
+        expr::BinaryFunc::Gte => expr::BinaryFunc::Lte,
+        other => other,
+    }
+}
+
+/// Groups the `Lt`/`Lte`/`Gt`/`Gte`/`Eq` predicates that compare a shared
+/// non-literal expression to a literal, replacing each group with its
+/// tightest lower and upper bound. Predicates that reference columns on
+/// both sides, or that are not one of these comparisons, are left alone.
+///
+/// Returns `true` if some group's bounds can never be satisfied together,
+/// meaning the whole set of predicates can never pass a row.
+fn tighten_comparisons(predicates: &mut Vec<MirScalarExpr>) -> bool {
+    let mut bounds: BTreeMap<MirScalarExpr, Bounds> = BTreeMap::new();
+    let mut rest = Vec::with_capacity(predicates.len());
+
+    for predicate in predicates.drain(..) {
+        let comparison = if let MirScalarExpr::CallBinary { func, expr1, expr2 } = &predicate {
+            match func {
+                expr::BinaryFunc::Lt
+                | expr::BinaryFunc::Lte
+                | expr::BinaryFunc::Gt
+                | expr::BinaryFunc::Gte
+                | expr::BinaryFunc::Eq => {
+                    if expr1.is_literal() && !expr2.is_literal() {
+                        Some((
+                            (**expr2).clone(),
+                            flip_comparison(*func),
+                            (**expr1).clone(),
+                        ))
+                    } else if expr2.is_literal() && !expr1.is_literal() {
+                        Some(((**expr1).clone(), *func, (**expr2).clone()))
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        let (expr, func, literal) = match comparison {
+            Some(triple) => triple,
+            None => {
+                rest.push(predicate);
+                continue;
+            }
+        };
+
+        let entry = bounds.entry(expr).or_default();
+        match func {
+            expr::BinaryFunc::Gt => entry.tighten_lower(literal, false),
+            expr::BinaryFunc::Gte => entry.tighten_lower(literal, true),
+            expr::BinaryFunc::Lt => entry.tighten_upper(literal, false),
+            expr::BinaryFunc::Lte => entry.tighten_upper(literal, true),
+            expr::BinaryFunc::Eq => {
+                entry.tighten_lower(literal.clone(), true);
+                entry.tighten_upper(literal, true);
+            }
+            _ => unreachable!("filtered to comparison funcs above"),
+        }
+    }
+
+    let mut contradiction = false;
+    for (expr, bound) in bounds {
+        if let (Some((lower, lower_inclusive)), Some((upper, upper_inclusive))) =
+            (&bound.lower, &bound.upper)
+        {
+            match literal_order(lower, upper) {
+                Some(Ordering::Greater) => {
+                    contradiction = true;
+                    continue;
+                }
+                Some(Ordering::Equal) if !(*lower_inclusive && *upper_inclusive) => {
+                    contradiction = true;
+                    continue;
+                }
+                Some(Ordering::Equal) => {
+                    // Both bounds pin the same inclusive value: `expr = v`.
+                    rest.push(expr.call_binary(lower.clone(), expr::BinaryFunc::Eq));
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        if let Some((value, inclusive)) = bound.lower {
+            let func = if inclusive {
+                expr::BinaryFunc::Gte
+            } else {
+                expr::BinaryFunc::Gt
+            };
+            rest.push(expr.clone().call_binary(value, func));
+        }
+        if let Some((value, inclusive)) = bound.upper {
+            let func = if inclusive {
+                expr::BinaryFunc::Lte
+            } else {
+                expr::BinaryFunc::Lt
+            };
+            rest.push(expr.call_binary(value, func));
+        }
+    }
+
+    *predicates = rest;
+    contradiction
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use repr::{Datum, RelationType, ScalarType};
+
+    fn int_input() -> MirRelationExpr {
+        MirRelationExpr::constant(
+            vec![],
+            RelationType::new(vec![ScalarType::Int64.nullable(false)]),
+        )
+    }
+
+    fn two_col_input() -> MirRelationExpr {
+        MirRelationExpr::constant(
+            vec![],
+            RelationType::new(vec![
+                ScalarType::Int64.nullable(false),
+                ScalarType::Int64.nullable(false),
+            ]),
+        )
+    }
+
+    fn literal(datum: Datum) -> MirScalarExpr {
+        MirScalarExpr::literal(Ok(datum), ScalarType::Int64)
+    }
+
+    fn assert_empty(mut expr: MirRelationExpr, input: &MirRelationExpr) {
+        Filter.action(&mut expr);
+        assert_eq!(expr, MirRelationExpr::constant(vec![], input.typ()));
+    }
+
+    #[test]
+    fn literal_false_collapses_to_empty() {
+        let input = int_input();
+        let expr = input
+            .clone()
+            .filter(vec![MirScalarExpr::literal(Ok(Datum::False), ScalarType::Bool)]);
+        assert_empty(expr, &input);
+    }
+
+    #[test]
+    fn literal_null_collapses_to_empty() {
+        let input = int_input();
+        let expr = input
+            .clone()
+            .filter(vec![MirScalarExpr::literal(Ok(Datum::Null), ScalarType::Bool)]);
+        assert_empty(expr, &input);
+    }
+
+    #[test]
+    fn unsatisfiable_range_collapses_to_empty() {
+        // `x >= 5 AND x < 5` can never be true.
+        let input = int_input();
+        let x = MirScalarExpr::Column(0);
+        let expr = input.clone().filter(vec![
+            x.clone()
+                .call_binary(literal(Datum::Int64(5)), expr::BinaryFunc::Gte),
+            x.call_binary(literal(Datum::Int64(5)), expr::BinaryFunc::Lt),
+        ]);
+        assert_empty(expr, &input);
+    }
+
+    #[test]
+    fn conflicting_equalities_collapse_to_empty() {
+        // `x = 5 AND x = 6` can never be true.
+        let input = int_input();
+        let x = MirScalarExpr::Column(0);
+        let expr = input.clone().filter(vec![
+            x.clone()
+                .call_binary(literal(Datum::Int64(5)), expr::BinaryFunc::Eq),
+            x.call_binary(literal(Datum::Int64(6)), expr::BinaryFunc::Eq),
+        ]);
+        assert_empty(expr, &input);
+    }
+
+    #[test]
+    fn satisfiable_range_is_not_collapsed() {
+        // `x > 3 AND x < 10` is satisfiable and must survive as a filter.
+        let input = int_input();
+        let x = MirScalarExpr::Column(0);
+        let mut expr = input.filter(vec![
+            x.clone()
+                .call_binary(literal(Datum::Int64(3)), expr::BinaryFunc::Gt),
+            x.call_binary(literal(Datum::Int64(10)), expr::BinaryFunc::Lt),
+        ]);
+        Filter.action(&mut expr);
+        match &expr {
+            MirRelationExpr::Filter { predicates, .. } => assert_eq!(predicates.len(), 2),
+            other => panic!("expected a satisfiable filter to survive fusion, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn repeated_lower_bound_tightens_to_the_stronger_one() {
+        // `x > 3 AND x > 5` is equivalent to just `x > 5`.
+        let x = MirScalarExpr::Column(0);
+        let mut expr = int_input().filter(vec![
+            x.clone()
+                .call_binary(literal(Datum::Int64(3)), expr::BinaryFunc::Gt),
+            x.clone()
+                .call_binary(literal(Datum::Int64(5)), expr::BinaryFunc::Gt),
+        ]);
+        Filter.action(&mut expr);
+        let correct = int_input().filter(vec![
+            x.call_binary(literal(Datum::Int64(5)), expr::BinaryFunc::Gt),
+        ]);
+        assert_eq!(expr, correct);
+    }
+
+    #[test]
+    fn equality_absorbs_a_compatible_range_bound() {
+        // `x = 5 AND x > 3` is equivalent to just `x = 5`.
+        let x = MirScalarExpr::Column(0);
+        let mut expr = int_input().filter(vec![
+            x.clone()
+                .call_binary(literal(Datum::Int64(5)), expr::BinaryFunc::Eq),
+            x.clone()
+                .call_binary(literal(Datum::Int64(3)), expr::BinaryFunc::Gt),
+        ]);
+        Filter.action(&mut expr);
+        let correct = int_input().filter(vec![
+            x.call_binary(literal(Datum::Int64(5)), expr::BinaryFunc::Eq),
+        ]);
+        assert_eq!(expr, correct);
+    }
+
+    #[test]
+    fn double_negation_is_eliminated() {
+        // `NOT(NOT(x > 3))` normalizes to `x > 3`.
+        let x = MirScalarExpr::Column(0);
+        let mut expr = int_input().filter(vec![x
+            .clone()
+            .call_binary(literal(Datum::Int64(3)), expr::BinaryFunc::Gt)
+            .call_unary(expr::UnaryFunc::Not)
+            .call_unary(expr::UnaryFunc::Not)]);
+        Filter.action(&mut expr);
+        let correct = int_input().filter(vec![
+            x.call_binary(literal(Datum::Int64(3)), expr::BinaryFunc::Gt),
+        ]);
+        assert_eq!(expr, correct);
+    }
+
+    #[test]
+    fn commutative_operands_are_ordered_so_equivalent_predicates_dedup() {
+        // `a = b` and `b = a` are the same predicate once operands are canonically ordered.
+        let a = MirScalarExpr::Column(0);
+        let b = MirScalarExpr::Column(1);
+        let mut expr = two_col_input().filter(vec![
+            a.clone().call_binary(b.clone(), expr::BinaryFunc::Eq),
+            b.call_binary(a, expr::BinaryFunc::Eq),
+        ]);
+        Filter.action(&mut expr);
+        match &expr {
+            MirRelationExpr::Filter { predicates, .. } => assert_eq!(predicates.len(), 1),
+            other => panic!("expected the duplicate equality to dedup, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn self_comparison_dedups_against_an_explicit_is_not_null() {
+        // `x = x` canonicalizes to `NOT(x IS NULL)`, which is what the user already wrote.
+        let x = MirScalarExpr::Column(0);
+        let mut expr = int_input().filter(vec![
+            x.clone().call_binary(x.clone(), expr::BinaryFunc::Eq),
+            x.clone().call_unary(expr::UnaryFunc::IsNull).call_unary(expr::UnaryFunc::Not),
+        ]);
+        Filter.action(&mut expr);
+        match &expr {
+            MirRelationExpr::Filter { predicates, .. } => assert_eq!(predicates.len(), 1),
+            other => panic!("expected the two equivalent predicates to dedup, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn constant_sub_expressions_are_folded_and_dropped() {
+        // `x > 0 AND (1 < 2)` folds the literal sub-expression to `true` and drops it,
+        // leaving just `x > 0`.
+        let x = MirScalarExpr::Column(0);
+        let mut expr = int_input().filter(vec![
+            x.clone().call_binary(literal(Datum::Int64(0)), expr::BinaryFunc::Gt),
+            literal(Datum::Int64(1)).call_binary(literal(Datum::Int64(2)), expr::BinaryFunc::Lt),
+        ]);
+        Filter.action(&mut expr);
+        let correct = int_input().filter(vec![
+            x.call_binary(literal(Datum::Int64(0)), expr::BinaryFunc::Gt),
+        ]);
+        assert_eq!(expr, correct);
+    }
+}